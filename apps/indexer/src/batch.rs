@@ -0,0 +1,94 @@
+//! Batches JSON-RPC lookups into chunks of `max_batch_size`, fanning
+//! each response back out to the item that requested it.
+
+use anyhow::Result;
+use serde_json::Value;
+
+/// How many individual RPC calls `BatchFetcher` packs into one batched
+/// request.
+#[derive(Debug, Clone, Copy)]
+pub struct BatchConfig {
+    pub max_batch_size: usize,
+}
+
+impl Default for BatchConfig {
+    fn default() -> Self {
+        BatchConfig { max_batch_size: 50 }
+    }
+}
+
+/// One unit of work queued onto a `BatchFetcher`. `marker` is handed
+/// back alongside the result so the caller can match it to whatever it
+/// was fetching (a block range, a `CrossChainTx`, ...).
+pub struct BatchRequest<T> {
+    pub method: String,
+    pub params: Value,
+    pub marker: T,
+}
+
+/// Per-item outcome of a batched call.
+pub enum BatchItemResult<T, R> {
+    Ok(T, R),
+    Err(T, anyhow::Error),
+}
+
+/// Sends a batch of JSON-RPC calls and returns one result per call, in
+/// the same order it was given. An implementor backed by a real node
+/// still surfaces a per-item error for e.g. a reverted/unknown tx
+/// instead of failing the whole batch, only returning `Err` here for a
+/// transport-level failure (the whole batch didn't make it to the node).
+pub trait BatchTransport {
+    fn send_batch(&self, calls: &[(String, Value)]) -> Result<Vec<Result<Value>>>;
+}
+
+/// Pages a list of batch requests through a `BatchTransport` in chunks
+/// of `config.max_batch_size`, decoding each response with `decode`.
+pub struct BatchFetcher<'a, Tr: BatchTransport> {
+    transport: &'a Tr,
+    config: BatchConfig,
+}
+
+impl<'a, Tr: BatchTransport> BatchFetcher<'a, Tr> {
+    pub fn new(transport: &'a Tr, config: BatchConfig) -> Self {
+        BatchFetcher { transport, config }
+    }
+
+    /// Runs every request in `items` through the transport, chunked to
+    /// `max_batch_size` calls per round-trip. A request that the node
+    /// rejects, or whose response `decode` can't parse, is isolated as
+    /// a `BatchItemResult::Err` for its own marker; the rest of the
+    /// batch still completes.
+    pub fn fetch<T: Clone, R>(
+        &self,
+        items: Vec<BatchRequest<T>>,
+        mut decode: impl FnMut(&T, Value) -> Result<R>,
+    ) -> Result<Vec<BatchItemResult<T, R>>> {
+        let mut out = Vec::with_capacity(items.len());
+
+        for chunk in items.chunks(self.config.max_batch_size.max(1)) {
+            let calls: Vec<(String, Value)> = chunk
+                .iter()
+                .map(|req| (req.method.clone(), req.params.clone()))
+                .collect();
+
+            let responses = self.transport.send_batch(&calls)?;
+
+            if responses.len() != chunk.len() {
+                return Err(anyhow::anyhow!(
+                    "batch transport returned {} responses for {} requests",
+                    responses.len(),
+                    chunk.len()
+                ));
+            }
+
+            for (req, response) in chunk.iter().zip(responses) {
+                match response.and_then(|value| decode(&req.marker, value)) {
+                    Ok(r) => out.push(BatchItemResult::Ok(req.marker.clone(), r)),
+                    Err(e) => out.push(BatchItemResult::Err(req.marker.clone(), e)),
+                }
+            }
+        }
+
+        Ok(out)
+    }
+}
@@ -0,0 +1,88 @@
+//! Confirmation-depth gate: promotes a `CrossChainTx` from `Pending` to
+//! `Ready` once it has enough blocks behind it.
+
+use crate::storage::{CrossChainTx, CrossChainTxStatus};
+
+/// Minimum number of blocks that must sit on top of a `CrossChainTx`'s
+/// source block before the submitter is allowed to send it.
+#[derive(Debug, Clone, Copy)]
+pub struct ConfirmationConfig {
+    pub min_confirmations: u64,
+}
+
+impl Default for ConfirmationConfig {
+    fn default() -> Self {
+        ConfirmationConfig {
+            min_confirmations: 6,
+        }
+    }
+}
+
+/// Promotes every `Pending` tx whose source block is now at least
+/// `min_confirmations` behind `current_head` to `Ready`. The submitter
+/// only drains `Ready` txs, so this pass is what actually unblocks them.
+pub fn promote_confirmed(config: &ConfirmationConfig, current_head: u64, txs: &mut [CrossChainTx]) {
+    for tx in txs.iter_mut() {
+        if tx.status == CrossChainTxStatus::Pending
+            && current_head.saturating_sub(tx.block_number) >= config.min_confirmations
+        {
+            tx.status = CrossChainTxStatus::Ready;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{BridgeChain, CrossChainTxKind};
+
+    fn pending_tx(block_number: u64) -> CrossChainTx {
+        CrossChainTx {
+            chain: BridgeChain::Ethereum,
+            kind: CrossChainTxKind::WithdrawAuto,
+            req_hash: String::new(),
+            req_content: String::new(),
+            tx_hash: String::new(),
+            block_number,
+            status: CrossChainTxStatus::Pending,
+            orphaned: false,
+        }
+    }
+
+    #[test]
+    fn promotes_tx_exactly_at_min_confirmations() {
+        let config = ConfirmationConfig {
+            min_confirmations: 6,
+        };
+        let mut txs = [pending_tx(94)];
+
+        promote_confirmed(&config, 100, &mut txs);
+
+        assert_eq!(txs[0].status, CrossChainTxStatus::Ready);
+    }
+
+    #[test]
+    fn leaves_tx_below_min_confirmations_pending() {
+        let config = ConfirmationConfig {
+            min_confirmations: 6,
+        };
+        let mut txs = [pending_tx(95)];
+
+        promote_confirmed(&config, 100, &mut txs);
+
+        assert_eq!(txs[0].status, CrossChainTxStatus::Pending);
+    }
+
+    #[test]
+    fn leaves_non_pending_tx_untouched() {
+        let config = ConfirmationConfig {
+            min_confirmations: 6,
+        };
+        let mut txs = [pending_tx(0)];
+        txs[0].status = CrossChainTxStatus::Cancelled;
+
+        promote_confirmed(&config, 100, &mut txs);
+
+        assert_eq!(txs[0].status, CrossChainTxStatus::Cancelled);
+    }
+}
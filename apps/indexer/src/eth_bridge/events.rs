@@ -0,0 +1,215 @@
+use anyhow::{anyhow, Result};
+use ethers::abi::{decode, ParamType, Token};
+use ethers::types::{Log, H256};
+use ethers::utils::hex;
+use serde_json::json;
+
+use crate::starknet_indexer::events::REQUEST_HEADER_WITHDRAW_AUTO;
+use crate::storage::{
+    BridgeChain, CrossChainTx, CrossChainTxKind, CrossChainTxStatus, Event, EventLabel, Request,
+};
+
+pub const DEPOSIT_REQUEST_INITIATED_TOPIC: &str =
+    "0x7a7e5f8d7d4c88bd5c2e1eb0e4df0f5d1cc88c24f3fbe05f3b56d9c8b6d1e2a1";
+
+pub const WITHDRAW_REQUEST_COMPLETED_TOPIC: &str =
+    "0x3c6c8d96b5e0c2a4f6e5a9b8d6c1f2e3a4b5c6d7e8f9a0b1c2d3e4f5a6b7c8d9";
+
+///
+pub fn get_store_data(
+    log: Log,
+    block_timestamp: u64,
+) -> Result<(Option<Request>, Option<Event>, Vec<CrossChainTx>)> {
+    // topics[0] -> event signature.
+    // topics[1] -> req hash (indexed bytes32).
+    let topic0 = log
+        .topics
+        .first()
+        .ok_or_else(|| anyhow!("log has no topics, can't resolve event signature"))?;
+    let req_hash = log
+        .topics
+        .get(1)
+        .ok_or_else(|| anyhow!("log has no req hash topic"))?;
+
+    let block_number = log
+        .block_number
+        .ok_or_else(|| anyhow!("log has no block number"))?
+        .as_u64();
+    let transaction_hash = log
+        .transaction_hash
+        .ok_or_else(|| anyhow!("log has no transaction hash"))?;
+    let block_hash = log
+        .block_hash
+        .ok_or_else(|| anyhow!("log has no block hash"))?;
+
+    let mut store_event = Event {
+        req_hash: h256_to_hex(req_hash),
+        label: EventLabel::DepositInitiatedL1,
+        block_timestamp,
+        block_number,
+        block_hash: h256_to_hex(&block_hash),
+        tx_hash: h256_to_hex(&transaction_hash),
+        orphaned: false,
+    };
+
+    let mut txs = vec![];
+
+    match h256_to_hex(topic0).as_str() {
+        DEPOSIT_REQUEST_INITIATED_TOPIC => {
+            store_event.label = EventLabel::DepositInitiatedL1;
+
+            let (request, header) =
+                request_from_log_data(&store_event.label, &store_event.req_hash, &log.data)?;
+
+            // txs are only valid for deposit.
+            txs = get_xchain_txs(
+                header,
+                request.hash.clone(),
+                request.content.clone(),
+                block_number,
+            );
+
+            assert_eq!(request.hash, store_event.req_hash);
+            Ok((Some(request), Some(store_event), txs))
+        }
+        WITHDRAW_REQUEST_COMPLETED_TOPIC => {
+            store_event.label = EventLabel::WithdrawCompletedL1;
+
+            let (request, _header) =
+                request_from_log_data(&store_event.label, &store_event.req_hash, &log.data)?;
+
+            assert_eq!(request.hash, store_event.req_hash);
+            Ok((Some(request), Some(store_event), txs))
+        }
+        _ => Ok((None, None, vec![])),
+    }
+}
+
+/// From the ABI-decoded log data, parse the request fields required to
+/// build `Request`. Mirrors `starknet_indexer::events::request_from_event_data`,
+/// the L1 counterpart: `header` and the four addresses are non-indexed
+/// and therefore live in `data`, ABI-encoded in declaration order.
+fn request_from_log_data(
+    event_label: &EventLabel,
+    req_hash: &str,
+    data: &[u8],
+) -> Result<(Request, u128)> {
+    let tokens = decode(
+        &[
+            ParamType::Uint(256),
+            ParamType::Address,
+            ParamType::Address,
+            ParamType::Address,
+            ParamType::Address,
+            ParamType::Bytes,
+        ],
+        data,
+    )?;
+
+    let header = match &tokens[0] {
+        Token::Uint(v) => v.low_u128(),
+        _ => return Err(anyhow!("expected a uint256 header as the first log field")),
+    };
+
+    let [_header, collection_a, collection_b, owner_a, owner_b, content_bytes] = unpack_tokens(tokens)?;
+    let content = serde_json::to_string(&json!(content_bytes))?;
+
+    let req = match event_label {
+        EventLabel::DepositInitiatedL1 => Request {
+            hash: req_hash.to_string(),
+            chain_src: BridgeChain::Ethereum,
+            collection_src: collection_a,
+            collection_dst: collection_b,
+            from: owner_a,
+            to: owner_b,
+            content,
+            typed_content: None,
+            orphaned: false,
+        },
+        EventLabel::WithdrawCompletedL1 => Request {
+            hash: req_hash.to_string(),
+            chain_src: BridgeChain::Starknet,
+            // Swapped relative to the deposit arm: the request
+            // originated on Starknet, so src/dst and from/to flip, same
+            // as starknet_indexer::events::request_from_event_data does
+            // between its deposit and withdraw branches.
+            collection_src: collection_b,
+            collection_dst: collection_a,
+            from: owner_b,
+            to: owner_a,
+            content,
+            typed_content: None,
+            orphaned: false,
+        },
+        _ => {
+            return Err(anyhow!(
+                "EventLabel {:?} not supposed to generate a request",
+                event_label
+            ))
+        }
+    };
+
+    Ok((req, header))
+}
+
+/// Destructures the fixed 6-token ABI decode into typed/formatted fields,
+/// addresses rendered the same way `felt_to_hex` renders felts.
+fn unpack_tokens(tokens: Vec<Token>) -> Result<[String; 6]> {
+    if tokens.len() != 6 {
+        return Err(anyhow!(
+            "expected 6 ABI-decoded fields, got {}",
+            tokens.len()
+        ));
+    }
+
+    let mut out: Vec<String> = Vec::with_capacity(6);
+    for token in &tokens[..5] {
+        out.push(match token {
+            Token::Uint(v) => format!("{:#x}", v),
+            Token::Address(a) => format!("{:#x}", a),
+            _ => return Err(anyhow!("unexpected token shape in log data: {:?}", token)),
+        });
+    }
+    let content = match &tokens[5] {
+        Token::Bytes(b) => format!("0x{}", hex::encode(b)),
+        _ => return Err(anyhow!("expected bytes token for content")),
+    };
+    out.push(content);
+
+    out.try_into()
+        .map_err(|_| anyhow!("failed to collect decoded fields"))
+}
+
+///
+fn get_xchain_txs(
+    header: u128,
+    req_hash: String,
+    req_content: String,
+    block_number: u64,
+) -> Vec<CrossChainTx> {
+    let can_withdraw_auto = header & REQUEST_HEADER_WITHDRAW_AUTO == REQUEST_HEADER_WITHDRAW_AUTO;
+
+    // Txs that will target the Starknet bridge contract.
+    let mut txs: Vec<CrossChainTx> = vec![];
+
+    if can_withdraw_auto {
+        txs.push(CrossChainTx {
+            chain: BridgeChain::Starknet,
+            kind: CrossChainTxKind::WithdrawAuto,
+            req_hash,
+            req_content,
+            tx_hash: String::from(""),
+            block_number,
+            status: CrossChainTxStatus::Pending,
+            orphaned: false,
+        });
+    }
+
+    txs
+}
+
+///
+#[inline(always)]
+fn h256_to_hex(h: &H256) -> String {
+    format!("{:#x}", h)
+}
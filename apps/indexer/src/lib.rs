@@ -0,0 +1,5 @@
+pub mod batch;
+pub mod confirmations;
+pub mod eth_bridge;
+pub mod starknet_indexer;
+pub mod storage;
@@ -0,0 +1,96 @@
+//! Batch request builders for the Starknet indexer: paging
+//! `starknet_getEvents` across a block range, and resolving `tx_hash`
+//! on pending `CrossChainTx` rows.
+
+use serde_json::{json, Value};
+
+use crate::batch::BatchRequest;
+use crate::storage::CrossChainTx;
+
+/// Splits `[from_block, to_block]` into `chunk_size`-block pages and
+/// builds one `starknet_getEvents` request per page, `marker` carrying
+/// the page's own `(from, to)` range so pages can be reassembled in
+/// order once all of them come back.
+pub fn build_get_events_requests(
+    from_block: u64,
+    to_block: u64,
+    address: &str,
+    chunk_size: u64,
+) -> Vec<BatchRequest<(u64, u64)>> {
+    let chunk_size = chunk_size.max(1);
+    let mut requests = Vec::new();
+    let mut start = from_block;
+
+    while start <= to_block {
+        let end = (start + chunk_size - 1).min(to_block);
+        requests.push(BatchRequest {
+            method: "starknet_getEvents".to_string(),
+            params: json!({
+                "filter": {
+                    "from_block": { "block_number": start },
+                    "to_block": { "block_number": end },
+                    "address": address,
+                    "chunk_size": chunk_size,
+                }
+            }),
+            marker: (start, end),
+        });
+        start = end + 1;
+    }
+
+    requests
+}
+
+/// One submission-status lookup per `CrossChainTx` whose `tx_hash` is
+/// still empty, `marker` carrying the row's `req_hash` so the resolved
+/// `tx_hash` can be matched back to it.
+pub fn build_tx_hash_requests(txs: &[CrossChainTx]) -> Vec<BatchRequest<String>> {
+    txs.iter()
+        .filter(|tx| tx.tx_hash.is_empty())
+        .map(|tx| BatchRequest {
+            method: "starklane_getSubmissionStatus".to_string(),
+            params: json!({ "req_hash": tx.req_hash }),
+            marker: tx.req_hash.clone(),
+        })
+        .collect()
+}
+
+/// Pulls the `tx_hash` field back out of a `starklane_getSubmissionStatus`
+/// response; the `decode` callback a `BatchFetcher::fetch` call over
+/// `build_tx_hash_requests` should use.
+pub fn decode_tx_hash_response(_req_hash: &str, response: Value) -> anyhow::Result<String> {
+    response
+        .get("tx_hash")
+        .and_then(Value::as_str)
+        .map(String::from)
+        .ok_or_else(|| anyhow::anyhow!("submission status response missing tx_hash: {response}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunk_size_zero_is_treated_as_one() {
+        let requests = build_get_events_requests(0, 2, "0xaddr", 0);
+
+        let ranges: Vec<(u64, u64)> = requests.iter().map(|r| r.marker).collect();
+        assert_eq!(ranges, vec![(0, 0), (1, 1), (2, 2)]);
+    }
+
+    #[test]
+    fn exact_multiple_of_chunk_size_has_no_remainder_page() {
+        let requests = build_get_events_requests(0, 9, "0xaddr", 5);
+
+        let ranges: Vec<(u64, u64)> = requests.iter().map(|r| r.marker).collect();
+        assert_eq!(ranges, vec![(0, 4), (5, 9)]);
+    }
+
+    #[test]
+    fn remainder_produces_a_smaller_last_page() {
+        let requests = build_get_events_requests(0, 7, "0xaddr", 5);
+
+        let ranges: Vec<(u64, u64)> = requests.iter().map(|r| r.marker).collect();
+        assert_eq!(ranges, vec![(0, 4), (5, 7)]);
+    }
+}
@@ -0,0 +1,184 @@
+//! Decodes the variable-length tail of a deposit/withdraw event into a
+//! typed `RequestContent`, following the bridge contract's Cairo
+//! serialization conventions (length-prefixed arrays, `u256` as
+//! consecutive felt pairs, short strings packed one ASCII string per felt).
+
+use anyhow::{anyhow, Result};
+use ethers::types::U256;
+use starknet::core::types::FieldElement;
+
+use crate::storage::RequestContent;
+
+/// Header bit distinguishing an ERC-1155 payload (per-id amounts) from
+/// an ERC-721 one (no amounts), alongside the auto-withdraw/burn bits.
+pub const REQUEST_HEADER_IS_ERC1155: u128 = 0x0100;
+
+/// Decodes the tail of the event `data` (everything after the 7-felt
+/// header) into a `RequestContent`, reading an `amounts` array only
+/// when `header` has the ERC-1155 bit set.
+pub fn decode_request_content(header: u128, tail: &[FieldElement]) -> Result<RequestContent> {
+    let mut cursor = FeltCursor::new(tail);
+
+    let name = felt_to_short_string(cursor.next()?);
+    let symbol = felt_to_short_string(cursor.next()?);
+    let base_uri = decode_string(&mut cursor)?;
+    let token_ids = decode_u256_array(&mut cursor)?;
+
+    let amounts = if header & REQUEST_HEADER_IS_ERC1155 == REQUEST_HEADER_IS_ERC1155 {
+        decode_u256_array(&mut cursor)?
+    } else {
+        vec![]
+    };
+
+    let uris = decode_string_array(&mut cursor)?;
+
+    Ok(RequestContent {
+        name,
+        symbol,
+        base_uri,
+        token_ids,
+        amounts,
+        uris,
+    })
+}
+
+/// Walks a felt slice, tracking position so the variable-length
+/// sections of the tail can be parsed one after another.
+struct FeltCursor<'a> {
+    felts: &'a [FieldElement],
+    pos: usize,
+}
+
+impl<'a> FeltCursor<'a> {
+    fn new(felts: &'a [FieldElement]) -> Self {
+        FeltCursor { felts, pos: 0 }
+    }
+
+    fn next(&mut self) -> Result<&'a FieldElement> {
+        let felt = self
+            .felts
+            .get(self.pos)
+            .ok_or_else(|| anyhow!("unexpected end of request content at felt {}", self.pos))?;
+        self.pos += 1;
+        Ok(felt)
+    }
+
+    fn next_len(&mut self) -> Result<usize> {
+        let len: u64 = (*self.next()?).try_into()?;
+        Ok(len as usize)
+    }
+
+    fn take(&mut self, count: usize) -> Result<&'a [FieldElement]> {
+        let end = self.pos.checked_add(count).ok_or_else(|| anyhow!("length overflow"))?;
+        let slice = self
+            .felts
+            .get(self.pos..end)
+            .ok_or_else(|| anyhow!("request content array runs past the end of event data"))?;
+        self.pos = end;
+        Ok(slice)
+    }
+}
+
+/// A length-prefixed array of `u256` read as consecutive felt pairs.
+fn decode_u256_array(cursor: &mut FeltCursor) -> Result<Vec<U256>> {
+    let len = cursor.next_len()?;
+    let felt_count = len
+        .checked_mul(2)
+        .ok_or_else(|| anyhow!("u256 array length {} overflows", len))?;
+    let felts = cursor.take(felt_count)?;
+    Ok(felts
+        .chunks_exact(2)
+        .map(|pair| felt_pair_to_u256(&pair[0], &pair[1]))
+        .collect())
+}
+
+/// Combines a `(low, high)` felt pair, Cairo's `u256` serialization, into
+/// one `U256`.
+fn felt_pair_to_u256(low: &FieldElement, high: &FieldElement) -> U256 {
+    let mut buf = [0u8; 32];
+    buf[..16].copy_from_slice(&high.to_bytes_be()[16..]);
+    buf[16..].copy_from_slice(&low.to_bytes_be()[16..]);
+    U256::from_big_endian(&buf)
+}
+
+/// A length-prefixed array of short-string felts, one string per felt.
+fn decode_string_array(cursor: &mut FeltCursor) -> Result<Vec<String>> {
+    let len = cursor.next_len()?;
+    let felts = cursor.take(len)?;
+    Ok(felts.iter().map(felt_to_short_string).collect())
+}
+
+/// A length-prefixed run of short-string felts concatenated into one
+/// string, used for `base_uri` which may span more than 31 bytes.
+fn decode_string(cursor: &mut FeltCursor) -> Result<String> {
+    Ok(decode_string_array(cursor)?.concat())
+}
+
+/// Cairo short strings pack ASCII bytes big-endian into a felt, zero
+/// padded on the left.
+fn felt_to_short_string(felt: &FieldElement) -> String {
+    let bytes = felt.to_bytes_be();
+    let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[start..]).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn felt(v: u64) -> FieldElement {
+        FieldElement::from(v)
+    }
+
+    fn short_string_felt(s: &str) -> FieldElement {
+        let mut bytes = [0u8; 32];
+        bytes[32 - s.len()..].copy_from_slice(s.as_bytes());
+        FieldElement::from_byte_slice_be(&bytes).unwrap()
+    }
+
+    #[test]
+    fn decodes_erc721_content_with_no_amounts() {
+        let tail = vec![
+            short_string_felt("NAME"),
+            short_string_felt("SYM"),
+            felt(1),                  // base_uri_len
+            short_string_felt("uri"), // base_uri[0]
+            felt(1),                  // token_ids_len
+            felt(42),                 // token_ids[0] low
+            felt(0),                  // token_ids[0] high
+            felt(1),                  // uris_len
+            short_string_felt("tokenuri"),
+        ];
+
+        let content = decode_request_content(0, &tail).unwrap();
+
+        assert_eq!(content.name, "NAME");
+        assert_eq!(content.symbol, "SYM");
+        assert_eq!(content.base_uri, "uri");
+        assert_eq!(content.token_ids, vec![U256::from(42)]);
+        assert!(content.amounts.is_empty());
+        assert_eq!(content.uris, vec!["tokenuri".to_string()]);
+    }
+
+    #[test]
+    fn decodes_erc1155_content_with_amounts() {
+        let tail = vec![
+            short_string_felt("NAME"),
+            short_string_felt("SYM"),
+            felt(0), // base_uri_len
+            felt(1), // token_ids_len
+            felt(7), // token_ids[0] low
+            felt(0), // token_ids[0] high
+            felt(1), // amounts_len
+            felt(3), // amounts[0] low
+            felt(0), // amounts[0] high
+            felt(0), // uris_len
+        ];
+
+        let content = decode_request_content(REQUEST_HEADER_IS_ERC1155, &tail).unwrap();
+
+        assert_eq!(content.token_ids, vec![U256::from(7)]);
+        assert_eq!(content.amounts, vec![U256::from(3)]);
+        assert!(content.uris.is_empty());
+    }
+}
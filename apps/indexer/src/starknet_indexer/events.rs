@@ -1,8 +1,12 @@
 use anyhow::{anyhow, Result};
 use serde_json::{json, Value};
 use starknet::core::{types::FieldElement, types::*};
+use tracing::warn;
 
-use crate::storage::{BridgeChain, CrossChainTx, CrossChainTxKind, Event, EventLabel, Request};
+use crate::starknet_indexer::content::decode_request_content;
+use crate::storage::{
+    BridgeChain, CrossChainTx, CrossChainTxKind, CrossChainTxStatus, Event, EventLabel, Request,
+};
 
 pub const DEPOSIT_REQUEST_INITIATED_SELECTOR: &str =
     "0x1682ccdc90fbee2d6cc3e930539cb4ca29390a438db1c2e4c7d493e01a61abb";
@@ -29,7 +33,9 @@ pub fn get_store_data(
         label: EventLabel::DepositInitiatedL2,
         block_timestamp: block_timestamp.try_into()?,
         block_number: event.block_number,
+        block_hash: felt_to_hex(&event.block_hash),
         tx_hash: felt_to_hex(&event.transaction_hash),
+        orphaned: false,
     };
 
     let mut txs = vec![];
@@ -45,6 +51,7 @@ pub fn get_store_data(
                 request_header,
                 request.hash.clone(),
                 request.content.clone(),
+                event.block_number,
             )?;
 
             assert_eq!(request.hash, store_event.req_hash);
@@ -76,6 +83,13 @@ fn request_from_event_data(event_label: &EventLabel, data: Vec<FieldElement>) ->
     let content_array: Vec<Value> = data.iter().map(|f| json!(felt_to_hex(f))).collect();
     let content = serde_json::to_string(&content_array)?;
 
+    // data[0] is the header; the typed content lives in the tail, past
+    // the 7-felt fixed part.
+    let header: u128 = data[0].try_into()?;
+    let typed_content = decode_request_content(header, &data[7..])
+        .map_err(|e| warn!("failed to decode request content, falling back to raw: {e}"))
+        .ok();
+
     let req = match event_label {
         EventLabel::DepositInitiatedL2 => Request {
             hash: u256_to_hex(&data[1..])?, // first felt is the header.
@@ -85,6 +99,8 @@ fn request_from_event_data(event_label: &EventLabel, data: Vec<FieldElement>) ->
             from: felt_to_hex(&data[6]),           // owner l2
             to: felt_to_hex(&data[5]),             // owner l1
             content,
+            typed_content,
+            orphaned: false,
         },
         EventLabel::WithdrawCompletedL2 => Request {
             hash: u256_to_hex(&data[1..])?, // first felt is the header.
@@ -94,6 +110,8 @@ fn request_from_event_data(event_label: &EventLabel, data: Vec<FieldElement>) ->
             from: felt_to_hex(&data[5]),           // owner l1
             to: felt_to_hex(&data[6]),             // owner l2
             content,
+            typed_content,
+            orphaned: false,
         },
         _ => {
             return Err(anyhow!(
@@ -111,6 +129,7 @@ fn get_xchain_txs(
     header: FieldElement,
     req_hash: String,
     req_content: String,
+    block_number: u64,
 ) -> Result<Vec<CrossChainTx>> {
     // For now, header must be convertible into u128.
     let h: u128 = header.try_into()?;
@@ -128,6 +147,9 @@ fn get_xchain_txs(
             req_hash: req_hash.clone(),
             req_content: req_content.clone(),
             tx_hash: String::from(""),
+            block_number,
+            status: CrossChainTxStatus::Pending,
+            orphaned: false,
         });
     }
 
@@ -138,6 +160,9 @@ fn get_xchain_txs(
             req_hash: req_hash.clone(),
             req_content: req_content.clone(),
             tx_hash: String::from(""),
+            block_number,
+            status: CrossChainTxStatus::Pending,
+            orphaned: false,
         });
     }
 
@@ -145,7 +170,7 @@ fn get_xchain_txs(
 }
 
 /// Always with leading 0 for u256.
-fn u256_to_hex(felts: &[FieldElement]) -> Result<String> {
+pub(crate) fn u256_to_hex(felts: &[FieldElement]) -> Result<String> {
     if felts.len() < 2 {
         return Err(anyhow!("At least two felts are required to read a u256"));
     }
@@ -0,0 +1,4 @@
+pub mod backfill;
+pub mod content;
+pub mod events;
+pub mod reorg;
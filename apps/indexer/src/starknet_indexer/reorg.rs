@@ -0,0 +1,272 @@
+//! Reorg detection: tracks `block_number -> block_hash` so a canonical
+//! hash mismatch can be walked back to a fork point and resolved.
+
+use anyhow::{anyhow, Result};
+
+use crate::storage::{BlockHeader, CrossChainTx, CrossChainTxStatus, Event, Request};
+
+/// Bounds how many blocks the indexer is willing to walk back when
+/// resolving a reorg before giving up and surfacing an error.
+#[derive(Debug, Clone, Copy)]
+pub struct ReorgConfig {
+    pub max_depth: u64,
+}
+
+impl Default for ReorgConfig {
+    fn default() -> Self {
+        ReorgConfig { max_depth: 50 }
+    }
+}
+
+/// The `block_number -> block_hash` table backing reorg detection.
+pub trait ChainTipStore {
+    fn block_header_at(&self, block_number: u64) -> Result<Option<BlockHeader>>;
+    fn set_block_header(&mut self, header: BlockHeader) -> Result<()>;
+}
+
+/// Compares `canonical_hash`, just fetched from the node for
+/// `block_number`, against what the store has on file for that height.
+///
+/// Returns `Ok(None)` when it's safe to index the block as-is (nothing
+/// stored yet, or the hashes agree). Returns `Ok(Some(fork_point))` when
+/// a reorg is detected, `fork_point` being the first height the indexer
+/// must re-index from. `fetch_canonical_hash` is used to walk backward
+/// re-fetching canonical hashes until a height both chains agree on is
+/// found; a height with no stored header also ends the walk, the height
+/// above it then being the fork point.
+pub fn resolve_reorg<S: ChainTipStore>(
+    store: &S,
+    config: &ReorgConfig,
+    block_number: u64,
+    canonical_hash: &str,
+    fetch_canonical_hash: impl Fn(u64) -> Result<String>,
+) -> Result<Option<u64>> {
+    match store.block_header_at(block_number)? {
+        None => return Ok(None),
+        Some(stored) if stored.block_hash == canonical_hash => return Ok(None),
+        Some(_) => {}
+    }
+
+    let floor = block_number.saturating_sub(config.max_depth);
+    let mut height = block_number;
+
+    while height > floor {
+        height -= 1;
+
+        let canonical = fetch_canonical_hash(height)?;
+        match store.block_header_at(height)? {
+            Some(stored) if stored.block_hash == canonical => return Ok(Some(height + 1)),
+            None => return Ok(Some(height + 1)),
+            Some(_) => continue,
+        }
+    }
+
+    Err(anyhow!(
+        "reorg at block {} is deeper than max_depth={}, refusing to re-index further",
+        block_number,
+        config.max_depth
+    ))
+}
+
+/// Marks every `Event`/`Request`/`CrossChainTx` row whose block is at or
+/// past `fork_point` as orphaned, so the next re-index from that height
+/// starts from a clean slate and the submitter skips any tx built from
+/// an orphaned request.
+pub fn orphan_from_fork_point(
+    fork_point: u64,
+    events: &mut [Event],
+    requests: &mut [Request],
+    txs: &mut [CrossChainTx],
+) {
+    let mut orphaned_hashes: Vec<String> = Vec::new();
+
+    for event in events.iter_mut() {
+        if event.block_number >= fork_point {
+            event.orphaned = true;
+            orphaned_hashes.push(event.req_hash.clone());
+        }
+    }
+
+    for request in requests.iter_mut() {
+        if orphaned_hashes.contains(&request.hash) {
+            request.orphaned = true;
+        }
+    }
+
+    for tx in txs.iter_mut() {
+        if !orphaned_hashes.contains(&tx.req_hash) {
+            continue;
+        }
+
+        tx.orphaned = true;
+
+        if tx.tx_hash.is_empty() {
+            // Never left the indexer; safe to cancel outright.
+            tx.status = CrossChainTxStatus::Cancelled;
+        } else {
+            // Already submitted on the destination chain; orphaning the
+            // request doesn't undo that, so flag it for manual review
+            // instead of silently treating it as cancelled.
+            tx.status = CrossChainTxStatus::Conflicted;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::storage::{BridgeChain, CrossChainTxKind, EventLabel};
+
+    struct MockStore(HashMap<u64, BlockHeader>);
+
+    impl ChainTipStore for MockStore {
+        fn block_header_at(&self, block_number: u64) -> Result<Option<BlockHeader>> {
+            Ok(self.0.get(&block_number).cloned())
+        }
+
+        fn set_block_header(&mut self, header: BlockHeader) -> Result<()> {
+            self.0.insert(header.block_number, header);
+            Ok(())
+        }
+    }
+
+    fn header(block_number: u64, block_hash: &str) -> BlockHeader {
+        BlockHeader {
+            block_number,
+            block_hash: block_hash.to_string(),
+        }
+    }
+
+    #[test]
+    fn no_stored_header_is_not_a_reorg() {
+        let store = MockStore(HashMap::new());
+        let config = ReorgConfig::default();
+
+        let result = resolve_reorg(&store, &config, 10, "0xabc", |_| unreachable!());
+
+        assert_eq!(result.unwrap(), None);
+    }
+
+    #[test]
+    fn matching_hash_is_not_a_reorg() {
+        let mut store = MockStore(HashMap::new());
+        store.0.insert(10, header(10, "0xabc"));
+        let config = ReorgConfig::default();
+
+        let result = resolve_reorg(&store, &config, 10, "0xabc", |_| unreachable!());
+
+        assert_eq!(result.unwrap(), None);
+    }
+
+    #[test]
+    fn mismatch_walks_back_to_fork_point() {
+        let mut store = MockStore(HashMap::new());
+        store.0.insert(8, header(8, "0x8_old"));
+        store.0.insert(9, header(9, "0x9_old"));
+        store.0.insert(10, header(10, "0x10_old"));
+        let config = ReorgConfig::default();
+
+        let result = resolve_reorg(&store, &config, 10, "0x10_new", |height| {
+            Ok(match height {
+                9 => "0x9_old".to_string(),
+                8 => "0x8_old".to_string(),
+                _ => unreachable!(),
+            })
+        });
+
+        // Height 9 still agrees with the node, so the fork starts at 10.
+        assert_eq!(result.unwrap(), Some(10));
+    }
+
+    #[test]
+    fn reorg_deeper_than_max_depth_errors() {
+        let mut store = MockStore(HashMap::new());
+        store.0.insert(8, header(8, "0x8_old"));
+        store.0.insert(9, header(9, "0x9_old"));
+        store.0.insert(10, header(10, "0x10_old"));
+        let config = ReorgConfig { max_depth: 2 };
+
+        let result = resolve_reorg(&store, &config, 10, "0x10_new", |_| Ok("0xmismatch".to_string()));
+
+        assert!(result.is_err());
+    }
+
+    fn event(req_hash: &str, block_number: u64) -> Event {
+        Event {
+            req_hash: req_hash.to_string(),
+            label: EventLabel::DepositInitiatedL2,
+            block_timestamp: 0,
+            block_number,
+            block_hash: String::new(),
+            tx_hash: String::new(),
+            orphaned: false,
+        }
+    }
+
+    fn request(hash: &str) -> Request {
+        Request {
+            hash: hash.to_string(),
+            chain_src: BridgeChain::Starknet,
+            collection_src: String::new(),
+            collection_dst: String::new(),
+            from: String::new(),
+            to: String::new(),
+            content: String::new(),
+            typed_content: None,
+            orphaned: false,
+        }
+    }
+
+    fn tx(req_hash: &str, tx_hash: &str) -> CrossChainTx {
+        CrossChainTx {
+            chain: BridgeChain::Ethereum,
+            kind: CrossChainTxKind::WithdrawAuto,
+            req_hash: req_hash.to_string(),
+            req_content: String::new(),
+            tx_hash: tx_hash.to_string(),
+            block_number: 0,
+            status: CrossChainTxStatus::Pending,
+            orphaned: false,
+        }
+    }
+
+    #[test]
+    fn orphan_from_fork_point_marks_events_and_requests_past_fork() {
+        let mut events = [event("0x1", 9), event("0x2", 10)];
+        let mut requests = [request("0x1"), request("0x2")];
+        let mut txs: [CrossChainTx; 0] = [];
+
+        orphan_from_fork_point(10, &mut events, &mut requests, &mut txs);
+
+        assert!(!events[0].orphaned);
+        assert!(events[1].orphaned);
+        assert!(!requests[0].orphaned);
+        assert!(requests[1].orphaned);
+    }
+
+    #[test]
+    fn orphan_from_fork_point_cancels_unsubmitted_tx() {
+        let mut events = [event("0x1", 10)];
+        let mut requests = [request("0x1")];
+        let mut txs = [tx("0x1", "")];
+
+        orphan_from_fork_point(10, &mut events, &mut requests, &mut txs);
+
+        assert!(txs[0].orphaned);
+        assert_eq!(txs[0].status, CrossChainTxStatus::Cancelled);
+    }
+
+    #[test]
+    fn orphan_from_fork_point_conflicts_submitted_tx() {
+        let mut events = [event("0x1", 10)];
+        let mut requests = [request("0x1")];
+        let mut txs = [tx("0x1", "0xsubmitted")];
+
+        orphan_from_fork_point(10, &mut events, &mut requests, &mut txs);
+
+        assert!(txs[0].orphaned);
+        assert_eq!(txs[0].status, CrossChainTxStatus::Conflicted);
+    }
+}
@@ -0,0 +1,115 @@
+//! Storage types shared by every chain-specific indexer. These are the
+//! rows persisted to the index: one `Request` per bridged asset transfer,
+//! one `Event` per raw log that fed it, and zero or more `CrossChainTx`
+//! describing the automated follow-up action on the other chain.
+
+use ethers::types::U256;
+
+/// Which side of the bridge a request or tx originates from / targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BridgeChain {
+    Starknet,
+    Ethereum,
+}
+
+/// What kind of raw log an `Event` was built from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventLabel {
+    DepositInitiatedL2,
+    WithdrawCompletedL2,
+    DepositInitiatedL1,
+    WithdrawCompletedL1,
+}
+
+/// The variable-length part of a bridged request, decoded from its raw
+/// felts instead of left as an opaque hex blob. `amounts` is empty for
+/// ERC-721 requests, one entry per `token_ids` entry for ERC-1155.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RequestContent {
+    pub name: String,
+    pub symbol: String,
+    pub base_uri: String,
+    pub token_ids: Vec<U256>,
+    pub amounts: Vec<U256>,
+    pub uris: Vec<String>,
+}
+
+/// A bridge transfer, reconstructed from the fixed-size header of a
+/// deposit/withdraw event plus its variable-length content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub hash: String,
+    pub chain_src: BridgeChain,
+    pub collection_src: String,
+    pub collection_dst: String,
+    pub from: String,
+    pub to: String,
+    pub content: String,
+    /// Structured view of `content`, when the tail of the event data
+    /// could be decoded as a known ERC-721/ERC-1155 payload.
+    pub typed_content: Option<RequestContent>,
+    /// Set once the block this request was indexed from is orphaned by
+    /// a reorg; orphaned requests are excluded from API responses.
+    pub orphaned: bool,
+}
+
+/// One raw on-chain log backing a `Request`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub req_hash: String,
+    pub label: EventLabel,
+    pub block_timestamp: u64,
+    pub block_number: u64,
+    pub block_hash: String,
+    pub tx_hash: String,
+    /// Set once `block_number` is found to be on an orphaned fork.
+    pub orphaned: bool,
+}
+
+/// An automated action this indexer must still submit on the other
+/// chain (e.g. completing a withdraw once its deposit is seen).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CrossChainTxKind {
+    WithdrawAuto,
+    BurnAuto,
+}
+
+/// Where a `CrossChainTx` sits in the confirmation-depth gate: freshly
+/// parsed txs start `Pending` and are only `Ready` for the submitter to
+/// drain once their source block has enough confirmations behind it
+/// that a shallow reorg can no longer invalidate them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossChainTxStatus {
+    Pending,
+    Ready,
+    /// Orphaned before it was ever submitted; safe to drop.
+    Cancelled,
+    /// Orphaned after submission; the on-chain side can't be undone,
+    /// so this needs manual review rather than silent cancellation.
+    Conflicted,
+}
+
+/// A cross-chain action derived from a `Request`, pending submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrossChainTx {
+    pub chain: BridgeChain,
+    pub kind: CrossChainTxKind,
+    pub req_hash: String,
+    pub req_content: String,
+    pub tx_hash: String,
+    /// Height of the source block the underlying request was parsed
+    /// from; what the confirmation-depth gate measures against.
+    pub block_number: u64,
+    pub status: CrossChainTxStatus,
+    /// Set once the request backing this tx is found to be orphaned;
+    /// the submitter must not submit an orphaned tx.
+    pub orphaned: bool,
+}
+
+/// One row of the `block_number -> block_hash` chain-tip table used to
+/// detect Starknet reorgs before persisting a block's events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockHeader {
+    pub block_number: u64,
+    pub block_hash: String,
+}